@@ -0,0 +1,414 @@
+use axum::{
+    extract::State,
+    http::{header, StatusCode},
+    middleware::Next,
+    extract::Request,
+    response::Response,
+    Json,
+};
+use jsonwebtoken::{decode, encode, DecodingKey, EncodingKey, Header, Validation};
+use serde::{Deserialize, Serialize};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::config::AuthConfig;
+use crate::error::ApiError;
+use crate::password;
+use crate::AppState;
+
+/// Where a login's credentials are actually checked.
+#[derive(Debug, Clone)]
+pub enum AuthBackend {
+    /// Check the local `users` table (Argon2id, or legacy bcrypt hashes).
+    Local,
+    /// Bind against an LDAP / Active Directory server.
+    Ldap(LdapConfig),
+}
+
+#[derive(Debug, Clone)]
+pub struct LdapConfig {
+    /// e.g. `ldap://dc1.example.com:389`
+    pub url: String,
+    /// `{username}` is replaced with the submitted username, e.g.
+    /// `uid={username},ou=people,dc=example,dc=com`
+    pub bind_dn_template: String,
+    /// Optional base DN used if we ever need to search for the user
+    /// instead of binding directly with a formatted DN.
+    pub search_base: Option<String>,
+}
+
+impl AuthBackend {
+    /// Builds the backend from the loaded `[auth]` config section. Falls
+    /// back to `Local` if `backend = "ldap"` is set without the required
+    /// fields, so a misconfigured directory can never lock out local admin
+    /// login.
+    pub fn from_config(config: &AuthConfig) -> Self {
+        if config.backend != "ldap" {
+            return AuthBackend::Local;
+        }
+
+        let Some(url) = config.ldap_url.clone() else {
+            eprintln!("auth.backend = \"ldap\" set but ldap_url is missing, falling back to local auth");
+            return AuthBackend::Local;
+        };
+        let Some(bind_dn_template) = config.ldap_bind_dn_template.clone() else {
+            eprintln!("auth.backend = \"ldap\" set but ldap_bind_dn_template is missing, falling back to local auth");
+            return AuthBackend::Local;
+        };
+
+        AuthBackend::Ldap(LdapConfig {
+            url,
+            bind_dn_template,
+            search_base: config.ldap_search_base.clone(),
+        })
+    }
+}
+
+/// Short-lived access tokens prove identity on regular API calls; refresh
+/// tokens only exist to mint fresh access tokens. Keeping them as distinct
+/// variants (instead of two structurally-identical `Claims`) means a
+/// refresh token stolen from storage can't be replayed against `/api/*`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TokenType {
+    #[serde(rename = "access")]
+    Access,
+    #[serde(rename = "refresh")]
+    Refresh,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Claims {
+    pub sub: String,
+    pub exp: usize,
+    pub iat: usize,
+    pub token_type: TokenType,
+    /// Mirrors `users.session_epoch` at the time the token was issued. A
+    /// logout (or password change) bumps the stored epoch, which instantly
+    /// invalidates every token minted before that point.
+    pub epoch: i64,
+}
+
+#[derive(Debug, Deserialize, utoipa::ToSchema)]
+pub struct LoginRequest {
+    pub username: String,
+    pub password: String,
+}
+
+#[derive(Serialize, utoipa::ToSchema)]
+pub struct LoginResponse {
+    pub access_token: String,
+    pub refresh_token: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RefreshRequest {
+    pub refresh_token: String,
+}
+
+#[derive(Serialize)]
+pub struct RefreshResponse {
+    pub access_token: String,
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/login",
+    request_body = LoginRequest,
+    responses((status = 200, body = LoginResponse), (status = 401, description = "Invalid credentials"))
+)]
+pub async fn login_handler(
+    State(state): State<AppState>,
+    Json(payload): Json<LoginRequest>,
+) -> Result<Json<LoginResponse>, ApiError> {
+    if payload.username.is_empty() || payload.password.is_empty() {
+        return Err(ApiError::MissingCredentials);
+    }
+
+    let auth_backend = AuthBackend::from_config(&state.config.auth);
+    let authenticated = match &auth_backend {
+        AuthBackend::Ldap(ldap_cfg) => {
+            match try_ldap_bind(ldap_cfg, &payload.username, &payload.password).await {
+                Ok(true) => {
+                    ensure_local_user_row(&state, &payload.username).await;
+                    true
+                }
+                Ok(false) => false,
+                Err(LdapBindError::TlsRequired(err)) => {
+                    eprintln!("LDAP StartTLS negotiation failed, refusing to send credentials: {err}");
+                    return Err(ApiError::InvalidCredentials);
+                }
+                Err(LdapBindError::Connection(err)) => {
+                    eprintln!("LDAP bind error, falling back to local auth: {err}");
+                    verify_local_password(&state, &payload.username, &payload.password).await?
+                }
+            }
+        }
+        AuthBackend::Local => verify_local_password(&state, &payload.username, &payload.password).await?,
+    };
+
+    if !authenticated {
+        return Err(ApiError::InvalidCredentials);
+    }
+
+    let epoch = session_epoch(&state, &payload.username).await?.ok_or(ApiError::InvalidCredentials)?;
+    let access_token = mint_token(&state, &payload.username, TokenType::Access, epoch)?;
+    let refresh_token = mint_token(&state, &payload.username, TokenType::Refresh, epoch)?;
+
+    Ok(Json(LoginResponse {
+        access_token,
+        refresh_token,
+    }))
+}
+
+pub async fn refresh_handler(
+    State(state): State<AppState>,
+    Json(payload): Json<RefreshRequest>,
+) -> Result<Json<RefreshResponse>, ApiError> {
+    let claims = decode_claims(&state, &payload.refresh_token)?;
+    if claims.token_type != TokenType::Refresh {
+        return Err(ApiError::InvalidToken);
+    }
+
+    let current_epoch = session_epoch(&state, &claims.sub).await?.ok_or(ApiError::InvalidToken)?;
+    if claims.epoch < current_epoch {
+        return Err(ApiError::InvalidToken);
+    }
+
+    let access_token = mint_token(&state, &claims.sub, TokenType::Access, current_epoch)?;
+    Ok(Json(RefreshResponse { access_token }))
+}
+
+/// Bumps `session_epoch` to now, instantly invalidating every access and
+/// refresh token outstanding for this user.
+pub async fn logout_handler(State(state): State<AppState>, req: Request) -> Result<StatusCode, ApiError> {
+    let claims = claims_from_request(&state, &req)?;
+
+    sqlx::query("UPDATE users SET session_epoch = ? WHERE username = ?")
+        .bind(now_secs())
+        .bind(&claims.sub)
+        .execute(&state.db)
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+pub(crate) fn now_secs() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_secs() as i64
+}
+
+/// Returns `Ok(None)` when the user row is gone (e.g. `delete-user`), which
+/// callers must treat as "reject the token" — defaulting to epoch `0` would
+/// make `claims.epoch < current_epoch` impossible to satisfy (epochs are
+/// always non-negative), letting a deleted account's tokens authenticate
+/// forever.
+async fn session_epoch(state: &AppState, username: &str) -> Result<Option<i64>, ApiError> {
+    let row = sqlx::query_as::<_, (i64,)>("SELECT session_epoch FROM users WHERE username = ?")
+        .bind(username)
+        .fetch_optional(&state.db)
+        .await?;
+
+    Ok(row.map(|(epoch,)| epoch))
+}
+
+fn mint_token(
+    state: &AppState,
+    username: &str,
+    token_type: TokenType,
+    epoch: i64,
+) -> Result<String, ApiError> {
+    let iat = now_secs() as usize;
+    let lifetime = match token_type {
+        TokenType::Access => state.config.auth.access_token_minutes as usize * 60,
+        TokenType::Refresh => state.config.auth.refresh_token_days as usize * 24 * 60 * 60,
+    };
+
+    let claims = Claims {
+        sub: username.to_string(),
+        exp: iat + lifetime,
+        iat,
+        token_type,
+        epoch,
+    };
+
+    encode(
+        &Header::default(),
+        &claims,
+        &EncodingKey::from_secret(state.config.auth.jwt_secret.as_bytes()),
+    )
+    .map_err(|_| ApiError::InvalidToken)
+}
+
+fn decode_claims(state: &AppState, token: &str) -> Result<Claims, ApiError> {
+    decode::<Claims>(
+        token,
+        &DecodingKey::from_secret(state.config.auth.jwt_secret.as_bytes()),
+        &Validation::default(),
+    )
+    .map(|data| data.claims)
+    .map_err(|_| ApiError::InvalidToken)
+}
+
+fn claims_from_request(state: &AppState, req: &Request) -> Result<Claims, ApiError> {
+    let token = req
+        .headers()
+        .get(header::AUTHORIZATION)
+        .and_then(|header| header.to_str().ok())
+        .and_then(|header| header.strip_prefix("Bearer "))
+        .ok_or(ApiError::MissingToken)?;
+
+    decode_claims(state, token)
+}
+
+async fn verify_local_password(
+    state: &AppState,
+    username: &str,
+    plain_password: &str,
+) -> Result<bool, ApiError> {
+    let user = sqlx::query_as::<_, (String,)>("SELECT password FROM users WHERE username = ?")
+        .bind(username)
+        .fetch_optional(&state.db)
+        .await?;
+
+    let Some((stored_hash,)) = user else {
+        return Ok(false);
+    };
+
+    if !password::verify_password(plain_password, &stored_hash) {
+        return Ok(false);
+    }
+
+    // Transparently upgrade legacy bcrypt hashes to Argon2id now that we
+    // know the plaintext is correct.
+    if password::detect_algorithm(&stored_hash) == password::HashAlgorithm::Bcrypt {
+        let upgraded = password::hash_password(plain_password);
+        let _ = sqlx::query("UPDATE users SET password = ? WHERE username = ?")
+            .bind(upgraded)
+            .bind(username)
+            .execute(&state.db)
+            .await;
+    }
+
+    Ok(true)
+}
+
+/// Distinguishes "the directory was unreachable" (caller may fall back to
+/// local auth) from "we couldn't guarantee an encrypted channel" (caller
+/// must fail closed — falling back would let a forced `StartTLS` downgrade
+/// silently redirect authentication to a different credential store).
+pub enum LdapBindError {
+    Connection(ldap3::LdapError),
+    TlsRequired(ldap3::LdapError),
+}
+
+/// Attempts a simple bind against the configured directory. Returns
+/// `Ok(true)` on a successful bind, `Ok(false)` on a clean auth rejection,
+/// and `Err` if the directory itself couldn't be reached or the channel
+/// couldn't be encrypted.
+///
+/// `ldaps://` connections are encrypted end-to-end. A plain `ldap://`
+/// connection is upgraded with `StartTLS` before the bind so the password
+/// never goes over the wire in cleartext, even when following the example
+/// `ldap://` config; if the server can't negotiate `StartTLS` the bind is
+/// aborted with `LdapBindError::TlsRequired`.
+async fn try_ldap_bind(cfg: &LdapConfig, username: &str, password: &str) -> Result<bool, LdapBindError> {
+    let (conn, mut ldap) = ldap3::LdapConnAsync::new(&cfg.url)
+        .await
+        .map_err(LdapBindError::Connection)?;
+    ldap3::drive!(conn);
+
+    if !cfg.url.starts_with("ldaps://") {
+        ldap.start_tls().await.map_err(LdapBindError::TlsRequired)?;
+    }
+
+    let bind_dn = cfg.bind_dn_template.replace("{username}", &escape_dn_value(username));
+    let result = ldap
+        .simple_bind(&bind_dn, password)
+        .await
+        .map_err(LdapBindError::Connection)?;
+    let authenticated = result.success().is_ok();
+
+    let _ = ldap.unbind().await;
+    Ok(authenticated)
+}
+
+/// Escapes a DN attribute value per RFC 4514 so a username can never break
+/// out of the `{username}` slot in `bind_dn_template` and inject extra DN
+/// components. Escapes the special characters (`, + " \ < > ; =`), a leading
+/// `#` or space, and a trailing space.
+fn escape_dn_value(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    let last_index = value.chars().count().saturating_sub(1);
+    for (i, ch) in value.chars().enumerate() {
+        let is_last = i == last_index;
+        match ch {
+            ',' | '+' | '"' | '\\' | '<' | '>' | ';' | '=' => {
+                escaped.push('\\');
+                escaped.push(ch);
+            }
+            '#' | ' ' if i == 0 => {
+                escaped.push('\\');
+                escaped.push(ch);
+            }
+            ' ' if is_last => {
+                escaped.push('\\');
+                escaped.push(ch);
+            }
+            _ => escaped.push(ch),
+        }
+    }
+    escaped
+}
+
+/// Auto-provisions a row in the local `users` table for a directory user so
+/// the rest of the app (which only knows about `users.username`) keeps
+/// working. The stored password is the hash of a clock-derived marker that
+/// is never handed out; it is still what `verify_local_password` checks when
+/// the directory is unreachable and login falls back to local auth.
+async fn ensure_local_user_row(state: &AppState, username: &str) {
+    let exists = sqlx::query("SELECT 1 FROM users WHERE username = ?")
+        .bind(username)
+        .fetch_optional(&state.db)
+        .await
+        .ok()
+        .flatten();
+
+    if exists.is_none() {
+        let placeholder = password::hash_password(&uuid_like_marker());
+        let _ = sqlx::query("INSERT INTO users (username, password) VALUES (?, ?)")
+            .bind(username)
+            .bind(placeholder)
+            .execute(&state.db)
+            .await;
+    }
+}
+
+/// Cheap unique-ish marker so provisioned LDAP accounts don't share one
+/// placeholder hash. Derived from the system clock, not from a random source.
+fn uuid_like_marker() -> String {
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_nanos();
+    format!("ldap-provisioned-{nanos}")
+}
+
+pub async fn auth_middleware(
+    State(state): State<AppState>,
+    req: Request,
+    next: Next,
+) -> Result<Response, ApiError> {
+    let claims = claims_from_request(&state, &req)?;
+
+    if claims.token_type != TokenType::Access {
+        return Err(ApiError::InvalidToken);
+    }
+
+    let current_epoch = session_epoch(&state, &claims.sub).await?.ok_or(ApiError::InvalidToken)?;
+    if claims.epoch < current_epoch {
+        return Err(ApiError::InvalidToken);
+    }
+
+    Ok(next.run(req).await)
+}
@@ -0,0 +1,114 @@
+use clap::{Parser, Subcommand};
+use sqlx::sqlite::SqlitePool;
+
+use crate::{auth, db, password};
+
+#[derive(Parser)]
+#[command(name = "rustpanel", about = "A small self-hosted server dashboard")]
+pub struct Cli {
+    /// Path to the TOML config file. Defaults to `RUSTPANEL_CONFIG` or `rustpanel.toml`.
+    #[arg(long, global = true)]
+    pub config: Option<String>,
+
+    #[command(subcommand)]
+    pub command: Option<Command>,
+}
+
+#[derive(Subcommand)]
+pub enum Command {
+    /// Start the HTTP server (default when no subcommand is given).
+    Serve {
+        #[arg(long)]
+        port: Option<String>,
+        #[arg(long)]
+        bind: Option<String>,
+    },
+    /// Create a new local user, prompting for a password.
+    CreateUser { username: String },
+    /// Reset an existing user's password, prompting for a new one.
+    ResetPassword { username: String },
+    /// Delete a local user.
+    DeleteUser { username: String },
+    /// List all local users.
+    ListUsers,
+}
+
+pub async fn run_create_user(pool: &SqlitePool, username: &str) {
+    let plain = prompt_password("New password: ");
+    let hashed = password::hash_password(&plain);
+
+    match sqlx::query("INSERT INTO users (username, password) VALUES (?, ?)")
+        .bind(username)
+        .bind(hashed)
+        .execute(pool)
+        .await
+    {
+        Ok(_) => println!("Created user '{username}'"),
+        Err(sqlx::Error::Database(db_err)) if db_err.is_unique_violation() => {
+            eprintln!("A user named '{username}' already exists")
+        }
+        Err(err) => eprintln!("Failed to create user '{username}': {err}"),
+    }
+}
+
+pub async fn run_reset_password(pool: &SqlitePool, username: &str) {
+    let plain = prompt_password("New password: ");
+    let hashed = password::hash_password(&plain);
+
+    // Bump session_epoch alongside the password so a stolen refresh/access
+    // token for this account stops working the moment the password is
+    // reset, not just the next time it naturally expires.
+    let result = sqlx::query("UPDATE users SET password = ?, session_epoch = ? WHERE username = ?")
+        .bind(hashed)
+        .bind(auth::now_secs())
+        .bind(username)
+        .execute(pool)
+        .await;
+
+    match result {
+        Ok(result) if result.rows_affected() == 0 => eprintln!("No such user '{username}'"),
+        Ok(_) => println!("Password reset for '{username}'"),
+        Err(err) => eprintln!("Failed to reset password for '{username}': {err}"),
+    }
+}
+
+pub async fn run_delete_user(pool: &SqlitePool, username: &str) {
+    let result = sqlx::query("DELETE FROM users WHERE username = ?")
+        .bind(username)
+        .execute(pool)
+        .await;
+
+    match result {
+        Ok(result) if result.rows_affected() == 0 => eprintln!("No such user '{username}'"),
+        Ok(_) => println!("Deleted user '{username}'"),
+        Err(err) => eprintln!("Failed to delete user '{username}': {err}"),
+    }
+}
+
+pub async fn run_list_users(pool: &SqlitePool) {
+    let result: Result<Vec<(String,)>, _> =
+        sqlx::query_as("SELECT username FROM users ORDER BY username")
+            .fetch_all(pool)
+            .await;
+
+    match result {
+        Ok(usernames) if usernames.is_empty() => println!("No users."),
+        Ok(usernames) => {
+            for (username,) in usernames {
+                println!("{username}");
+            }
+        }
+        Err(err) => eprintln!("Failed to list users: {err}"),
+    }
+}
+
+/// Connects to the database and ensures the schema exists. Unlike `serve`,
+/// this does NOT seed the default admin — running e.g. `list-users` or
+/// `delete-user admin` must not resurrect the account it just removed.
+pub async fn connect(database_url: &str) -> SqlitePool {
+    db::connect_and_migrate(database_url).await
+}
+
+fn prompt_password(prompt: &str) -> String {
+    rpassword::prompt_password(prompt).expect("failed to read password")
+}
@@ -0,0 +1,147 @@
+use serde::Deserialize;
+use std::env;
+use std::fs;
+
+const DEFAULT_SERVICES: [&str; 5] = ["nginx", "mysql", "ssh", "docker", "cron"];
+
+/// Full application configuration, loaded from `rustpanel.toml` (path via
+/// `--config` or `RUSTPANEL_CONFIG`) and then overridden by environment
+/// variables. Anything not set in either place falls back to the defaults
+/// below, so a bare `rustpanel serve` with just `JWT_SECRET`/`DATABASE_URL`
+/// set still works exactly as before.
+#[derive(Debug, Clone, Deserialize, Default)]
+#[serde(default)]
+pub struct Config {
+    pub server: ServerConfig,
+    pub auth: AuthConfig,
+    pub services: ServicesConfig,
+    pub files: FilesConfig,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct ServerConfig {
+    pub bind: String,
+    pub port: u16,
+}
+
+impl Default for ServerConfig {
+    fn default() -> Self {
+        Self {
+            bind: "0.0.0.0".to_string(),
+            port: 3000,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct AuthConfig {
+    pub jwt_secret: String,
+    pub access_token_minutes: u64,
+    pub refresh_token_days: u64,
+    /// "local" or "ldap".
+    pub backend: String,
+    pub ldap_url: Option<String>,
+    pub ldap_bind_dn_template: Option<String>,
+    pub ldap_search_base: Option<String>,
+}
+
+impl Default for AuthConfig {
+    fn default() -> Self {
+        Self {
+            jwt_secret: String::new(),
+            access_token_minutes: 15,
+            refresh_token_days: 30,
+            backend: "local".to_string(),
+            ldap_url: None,
+            ldap_bind_dn_template: None,
+            ldap_search_base: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct ServicesConfig {
+    pub monitored: Vec<String>,
+}
+
+impl Default for ServicesConfig {
+    fn default() -> Self {
+        Self {
+            monitored: DEFAULT_SERVICES.iter().map(|s| s.to_string()).collect(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct FilesConfig {
+    pub root: String,
+}
+
+impl Default for FilesConfig {
+    fn default() -> Self {
+        Self { root: ".".to_string() }
+    }
+}
+
+impl Config {
+    /// Loads `rustpanel.toml` (if present) and layers environment variable
+    /// overrides on top, in that order: built-in defaults < file < env.
+    pub fn load(config_path: Option<String>) -> Self {
+        let path = config_path
+            .or_else(|| env::var("RUSTPANEL_CONFIG").ok())
+            .unwrap_or_else(|| "rustpanel.toml".to_string());
+
+        let mut config: Config = fs::read_to_string(&path)
+            .ok()
+            .and_then(|contents| match toml::from_str(&contents) {
+                Ok(config) => Some(config),
+                Err(err) => {
+                    eprintln!("Failed to parse {path}, ignoring it: {err}");
+                    None
+                }
+            })
+            .unwrap_or_default();
+
+        if let Ok(port) = env::var("PORT").and_then(|v| v.parse().map_err(|_| env::VarError::NotPresent)) {
+            config.server.port = port;
+        }
+        if let Ok(bind) = env::var("BIND") {
+            config.server.bind = bind;
+        }
+        if let Ok(jwt_secret) = env::var("JWT_SECRET") {
+            config.auth.jwt_secret = jwt_secret;
+        }
+        if let Ok(backend) = env::var("AUTH_BACKEND") {
+            config.auth.backend = backend;
+        }
+        if let Ok(ldap_url) = env::var("LDAP_URL") {
+            config.auth.ldap_url = Some(ldap_url);
+        }
+        if let Ok(tpl) = env::var("LDAP_BIND_DN_TEMPLATE") {
+            config.auth.ldap_bind_dn_template = Some(tpl);
+        }
+        if let Ok(search_base) = env::var("LDAP_SEARCH_BASE") {
+            config.auth.ldap_search_base = Some(search_base);
+        }
+        if let Ok(services) = env::var("RUSTPANEL_SERVICES") {
+            config.services.monitored = services
+                .split(',')
+                .map(|s| s.trim().to_string())
+                .filter(|s| !s.is_empty())
+                .collect();
+        }
+        if let Ok(root) = env::var("RUSTPANEL_FILES_ROOT") {
+            config.files.root = root;
+        }
+
+        if config.auth.jwt_secret.is_empty() {
+            panic!("JWT_SECRET must be set via the environment or `jwt_secret` in rustpanel.toml");
+        }
+
+        config
+    }
+}
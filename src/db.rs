@@ -0,0 +1,53 @@
+use sqlx::sqlite::SqlitePool;
+
+use crate::password;
+
+/// Connects to the configured database and ensures the schema exists. Used
+/// by both the server and the user-management CLI subcommands so they
+/// never drift apart.
+pub async fn connect_and_migrate(database_url: &str) -> SqlitePool {
+    let pool = SqlitePool::connect(database_url)
+        .await
+        .expect("Failed to connect to database");
+
+    sqlx::query(
+        "CREATE TABLE IF NOT EXISTS users (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            username TEXT NOT NULL UNIQUE,
+            password TEXT NOT NULL,
+            session_epoch INTEGER NOT NULL DEFAULT 0
+        )",
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    // `session_epoch` was added after the table may already exist from an
+    // older deployment; ignore the error when the column is already there.
+    let _ = sqlx::query("ALTER TABLE users ADD COLUMN session_epoch INTEGER NOT NULL DEFAULT 0")
+        .execute(&pool)
+        .await;
+
+    pool
+}
+
+/// Seeds the `admin` / `password` account whenever no user named `admin`
+/// exists, so a fresh install always has a way in. Other users in the table
+/// do not prevent the seed.
+pub async fn seed_default_admin(pool: &SqlitePool) {
+    let admin_exists = sqlx::query("SELECT 1 FROM users WHERE username = 'admin'")
+        .fetch_optional(pool)
+        .await
+        .unwrap();
+
+    if admin_exists.is_none() {
+        let hashed = password::hash_password("password");
+        sqlx::query("INSERT INTO users (username, password) VALUES (?, ?)")
+            .bind("admin")
+            .bind(hashed)
+            .execute(pool)
+            .await
+            .unwrap();
+        println!("👤 Created default admin user (admin / password)");
+    }
+}
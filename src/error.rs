@@ -0,0 +1,76 @@
+use axum::{
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+use serde::Serialize;
+use thiserror::Error;
+
+/// Unified error type for every handler. Each variant knows its own status
+/// code and a message safe to send back to the client, so callers never
+/// have to remember to map an internal failure to the right `StatusCode`.
+#[derive(Debug, Error)]
+pub enum ApiError {
+    #[error("username and password are required")]
+    MissingCredentials,
+    #[error("invalid username or password")]
+    InvalidCredentials,
+    #[error("missing bearer token")]
+    MissingToken,
+    #[error("invalid or expired token")]
+    InvalidToken,
+    #[error("database error: {0}")]
+    Database(#[from] sqlx::Error),
+    #[error("forbidden")]
+    Forbidden,
+    #[error("not found")]
+    NotFound,
+    #[error("{0}")]
+    BadRequest(String),
+    #[error("upload too large")]
+    PayloadTooLarge,
+}
+
+#[derive(Serialize)]
+struct ErrorBody {
+    status: u16,
+    message: String,
+}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        let status = match &self {
+            ApiError::MissingCredentials => StatusCode::BAD_REQUEST,
+            ApiError::InvalidCredentials => StatusCode::UNAUTHORIZED,
+            ApiError::MissingToken => StatusCode::UNAUTHORIZED,
+            ApiError::InvalidToken => StatusCode::UNAUTHORIZED,
+            ApiError::Database(err) if is_unique_violation(err) => StatusCode::CONFLICT,
+            ApiError::Database(_) => StatusCode::INTERNAL_SERVER_ERROR,
+            ApiError::Forbidden => StatusCode::FORBIDDEN,
+            ApiError::NotFound => StatusCode::NOT_FOUND,
+            ApiError::BadRequest(_) => StatusCode::BAD_REQUEST,
+            ApiError::PayloadTooLarge => StatusCode::PAYLOAD_TOO_LARGE,
+        };
+
+        // A `Database` error's `Display` carries raw sqlx/SQLite text
+        // (schema/query details); never forward that to the client.
+        let message = match &self {
+            ApiError::Database(err) if is_unique_violation(err) => {
+                "a user with that name already exists".to_string()
+            }
+            ApiError::Database(_) => "internal error".to_string(),
+            other => other.to_string(),
+        };
+
+        let body = ErrorBody {
+            status: status.as_u16(),
+            message,
+        };
+
+        (status, Json(body)).into_response()
+    }
+}
+
+fn is_unique_violation(err: &sqlx::Error) -> bool {
+    matches!(err, sqlx::Error::Database(db_err) if db_err.is_unique_violation())
+}
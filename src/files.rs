@@ -0,0 +1,177 @@
+use axum::{
+    body::Bytes,
+    extract::{Multipart, Query, State},
+    http::{header, StatusCode},
+    response::{IntoResponse, Response},
+    Json,
+};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::error::ApiError;
+use crate::AppState;
+
+#[derive(Serialize, utoipa::ToSchema)]
+pub struct FileInfo {
+    name: String,
+    is_dir: bool,
+    size: u64,
+}
+
+#[derive(Deserialize)]
+pub struct FileQuery {
+    pub path: Option<String>,
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/files",
+    params(("path" = Option<String>, Query, description = "Directory to list, relative to the configured files root")),
+    responses((status = 200, body = Vec<FileInfo>), (status = 403, description = "Path escapes the allowed root"), (status = 404, description = "Directory not found")),
+    security(("bearer_auth" = []))
+)]
+pub async fn list_files(
+    State(state): State<AppState>,
+    Query(params): Query<FileQuery>,
+) -> Result<Json<Vec<FileInfo>>, ApiError> {
+    let dir = confine(&state.config.files.root, params.path.as_deref())?;
+
+    let entries = fs::read_dir(&dir).map_err(|_| ApiError::NotFound)?;
+    let mut files = Vec::new();
+    for entry in entries.flatten() {
+        if let Ok(metadata) = entry.metadata() {
+            files.push(FileInfo {
+                name: entry.file_name().to_string_lossy().to_string(),
+                is_dir: metadata.is_dir(),
+                size: metadata.len(),
+            });
+        }
+    }
+    files.sort_by(|a, b| {
+        if a.is_dir == b.is_dir {
+            a.name.to_lowercase().cmp(&b.name.to_lowercase())
+        } else {
+            b.is_dir.cmp(&a.is_dir)
+        }
+    });
+    Ok(Json(files))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/files/download",
+    params(("path" = String, Query, description = "File to download, relative to the configured files root")),
+    responses((status = 200, description = "File contents"), (status = 403, description = "Path escapes the allowed root"), (status = 404, description = "File not found")),
+    security(("bearer_auth" = []))
+)]
+pub async fn download_file(
+    State(state): State<AppState>,
+    Query(params): Query<FileQuery>,
+) -> Result<Response, ApiError> {
+    let path = params.path.ok_or_else(|| ApiError::BadRequest("path is required".to_string()))?;
+    let resolved = confine(&state.config.files.root, Some(&path))?;
+
+    if !resolved.is_file() {
+        return Err(ApiError::NotFound);
+    }
+
+    let bytes = fs::read(&resolved).map_err(|_| ApiError::NotFound)?;
+    let file_name = resolved.file_name().map(|n| n.to_string_lossy().to_string()).unwrap_or_default();
+
+    Ok((
+        [
+            (header::CONTENT_TYPE, "application/octet-stream".to_string()),
+            (header::CONTENT_DISPOSITION, format!("attachment; filename=\"{file_name}\"")),
+        ],
+        bytes,
+    )
+        .into_response())
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/files",
+    params(("path" = String, Query, description = "File to delete, relative to the configured files root")),
+    responses((status = 204, description = "Deleted"), (status = 403, description = "Path escapes the allowed root"), (status = 404, description = "File not found")),
+    security(("bearer_auth" = []))
+)]
+pub async fn delete_file(
+    State(state): State<AppState>,
+    Query(params): Query<FileQuery>,
+) -> Result<StatusCode, ApiError> {
+    let path = params.path.ok_or_else(|| ApiError::BadRequest("path is required".to_string()))?;
+    let resolved = confine(&state.config.files.root, Some(&path))?;
+
+    if !resolved.is_file() {
+        return Err(ApiError::NotFound);
+    }
+
+    fs::remove_file(&resolved).map_err(|_| ApiError::NotFound)?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/files/upload",
+    params(("path" = Option<String>, Query, description = "Destination directory, relative to the configured files root")),
+    responses((status = 201, description = "Uploaded"), (status = 403, description = "Path escapes the allowed root"), (status = 413, description = "File too large")),
+    security(("bearer_auth" = []))
+)]
+pub async fn upload_file(
+    State(state): State<AppState>,
+    Query(params): Query<FileQuery>,
+    mut multipart: Multipart,
+) -> Result<StatusCode, ApiError> {
+    let dir = params.path.unwrap_or_else(|| ".".to_string());
+
+    while let Some(field) = multipart
+        .next_field()
+        .await
+        .map_err(|_| ApiError::BadRequest("invalid multipart body".to_string()))?
+    {
+        let Some(file_name) = field.file_name().map(|s| s.to_string()) else {
+            continue;
+        };
+
+        let data: Bytes = field.bytes().await.map_err(|_| ApiError::PayloadTooLarge)?;
+        let relative = Path::new(&dir).join(&file_name);
+        let target = confine_for_write(&state.config.files.root, &relative)?;
+
+        fs::write(&target, &data).map_err(|_| ApiError::NotFound)?;
+    }
+
+    Ok(StatusCode::CREATED)
+}
+
+/// Resolves `requested` against `root`, canonicalizing both so `..`
+/// segments, absolute paths and symlinks can't escape the confined
+/// directory. Requires the target to already exist.
+fn confine(root: &str, requested: Option<&str>) -> Result<PathBuf, ApiError> {
+    let root_canon = Path::new(root).canonicalize().map_err(|_| ApiError::NotFound)?;
+    let candidate = root_canon.join(requested.unwrap_or("."));
+    let resolved = candidate.canonicalize().map_err(|_| ApiError::NotFound)?;
+
+    if !resolved.starts_with(&root_canon) {
+        return Err(ApiError::Forbidden);
+    }
+
+    Ok(resolved)
+}
+
+/// Same confinement check as [`confine`], but for a file that doesn't
+/// exist yet: canonicalizes the parent directory instead of the file
+/// itself and re-joins the file name.
+fn confine_for_write(root: &str, requested: &Path) -> Result<PathBuf, ApiError> {
+    let root_canon = Path::new(root).canonicalize().map_err(|_| ApiError::NotFound)?;
+    let candidate = root_canon.join(requested);
+
+    let parent = candidate.parent().ok_or(ApiError::Forbidden)?;
+    let parent_canon = parent.canonicalize().map_err(|_| ApiError::NotFound)?;
+    if !parent_canon.starts_with(&root_canon) {
+        return Err(ApiError::Forbidden);
+    }
+
+    let file_name = candidate.file_name().ok_or(ApiError::Forbidden)?;
+    Ok(parent_canon.join(file_name))
+}
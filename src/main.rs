@@ -1,34 +1,47 @@
+mod auth;
+mod cli;
+mod config;
+mod db;
+mod error;
+mod files;
+mod openapi;
+mod password;
+mod services;
+
 use axum::{
-    extract::{Query, State, Request},
-    http::{StatusCode, header},
-    middleware::{self, Next},
-    response::{IntoResponse, Response},
+    extract::{Query, State},
+    middleware,
     routing::{get, post},
     Json, Router,
 };
-use std::fs;
-use std::path::Path;
-use jsonwebtoken::{decode, encode, DecodingKey, EncodingKey, Header, Validation};
-use serde::{Deserialize, Serialize};
+use serde::Serialize;
 use std::sync::{Arc, Mutex};
 use sysinfo::{CpuRefreshKind, Disks, MemoryRefreshKind, RefreshKind, System};
 use tokio::net::TcpListener;
+use tower_http::compression::CompressionLayer;
 use tower_http::cors::CorsLayer;
-use std::time::{SystemTime, UNIX_EPOCH};
 use sqlx::sqlite::SqlitePool;
+use clap::Parser;
 use dotenvy::dotenv;
 use std::env;
-use bcrypt::{hash, verify, DEFAULT_COST};
+use utoipa::OpenApi;
+use utoipa_swagger_ui::SwaggerUi;
+
+use auth::{auth_middleware, login_handler, logout_handler, refresh_handler};
+use cli::{Cli, Command};
+use config::Config;
+use files::{delete_file, download_file, list_files, upload_file};
+use services::{control_service, get_services};
 
 #[derive(Clone)]
 struct AppState {
     sys: Arc<Mutex<System>>,
     disks: Arc<Mutex<Disks>>,
     db: SqlitePool,
-    jwt_secret: String,
+    config: Arc<Config>,
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, utoipa::ToSchema)]
 struct SystemMetrics {
     cpu_usage: f32,
     total_memory: u64,
@@ -41,24 +54,7 @@ struct SystemMetrics {
     host_name: String,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
-struct Claims {
-    sub: String,
-    exp: usize,
-}
-
-#[derive(Debug, Deserialize)]
-struct LoginRequest {
-    username: String,
-    password: String,
-}
-
-#[derive(Serialize)]
-struct LoginResponse {
-    token: String,
-}
-
-#[derive(Serialize)]
+#[derive(Serialize, utoipa::ToSchema)]
 struct ProcessInfo {
     pid: u32,
     name: String,
@@ -66,53 +62,50 @@ struct ProcessInfo {
     memory: u64,
 }
 
-#[derive(Serialize)]
-struct ServiceInfo {
-    name: String,
-    status: String,
-    description: String,
-}
-
-#[derive(Serialize)]
-struct FileInfo {
-    name: String,
-    is_dir: bool,
-    size: u64,
-}
-
-#[derive(Deserialize)]
-struct FileQuery {
-    path: Option<String>,
-}
-
 #[tokio::main]
 async fn main() {
     dotenv().ok();
-    let database_url = env::var("DATABASE_URL").expect("DATABASE_URL must be set");
-    let jwt_secret = env::var("JWT_SECRET").expect("JWT_SECRET must be set");
-    let port = env::var("PORT").unwrap_or_else(|_| "3000".to_string());
+    let cli = Cli::parse();
 
-    let pool = SqlitePool::connect(&database_url).await.expect("Failed to connect to database");
-    
-    sqlx::query(
-        "CREATE TABLE IF NOT EXISTS users (
-            id INTEGER PRIMARY KEY AUTOINCREMENT,
-            username TEXT NOT NULL UNIQUE,
-            password TEXT NOT NULL
-        )"
-    ).execute(&pool).await.unwrap();
+    match cli.command.unwrap_or(Command::Serve { port: None, bind: None }) {
+        Command::Serve { port, bind } => {
+            let config = Config::load(cli.config);
+            serve(config, port, bind).await
+        }
+        Command::CreateUser { username } => {
+            let pool = cli::connect(&database_url()).await;
+            cli::run_create_user(&pool, &username).await;
+        }
+        Command::ResetPassword { username } => {
+            let pool = cli::connect(&database_url()).await;
+            cli::run_reset_password(&pool, &username).await;
+        }
+        Command::DeleteUser { username } => {
+            let pool = cli::connect(&database_url()).await;
+            cli::run_delete_user(&pool, &username).await;
+        }
+        Command::ListUsers => {
+            let pool = cli::connect(&database_url()).await;
+            cli::run_list_users(&pool).await;
+        }
+    }
+}
 
-    let admin_exists = sqlx::query("SELECT 1 FROM users WHERE username = 'admin'")
-        .fetch_optional(&pool).await.unwrap();
+fn database_url() -> String {
+    env::var("DATABASE_URL").expect("DATABASE_URL must be set")
+}
 
-    if admin_exists.is_none() {
-        let hashed = hash("password", DEFAULT_COST).unwrap();
-        sqlx::query("INSERT INTO users (username, password) VALUES (?, ?)")
-            .bind("admin")
-            .bind(hashed)
-            .execute(&pool).await.unwrap();
-        println!("👤 Created default admin user (admin / password)");
+async fn serve(mut config: Config, port_arg: Option<String>, bind_arg: Option<String>) {
+    let database_url = database_url();
+    if let Some(port) = port_arg.and_then(|p| p.parse().ok()) {
+        config.server.port = port;
     }
+    if let Some(bind) = bind_arg {
+        config.server.bind = bind;
+    }
+
+    let pool = db::connect_and_migrate(&database_url).await;
+    db::seed_default_admin(&pool).await;
 
     let sys = System::new_with_specifics(
         RefreshKind::new()
@@ -121,94 +114,45 @@ async fn main() {
     );
     let disks = Disks::new_with_refreshed_list();
 
+    let bind = config.server.bind.clone();
+    let port = config.server.port;
+
     let state = AppState {
         sys: Arc::new(Mutex::new(sys)),
         disks: Arc::new(Mutex::new(disks)),
         db: pool,
-        jwt_secret,
+        config: Arc::new(config),
     };
 
     let app = Router::new()
         .route("/api/system", get(get_system_metrics))
         .route("/api/processes", get(get_processes))
         .route("/api/services", get(get_services))
-        .route("/api/files", get(list_files))
+        .route("/api/services/:name/:action", post(control_service))
+        .route("/api/files", get(list_files).delete(delete_file))
+        .route("/api/files/download", get(download_file))
+        .route("/api/files/upload", post(upload_file))
+        .route("/api/logout", post(logout_handler))
         .route_layer(middleware::from_fn_with_state(state.clone(), auth_middleware))
         .route("/api/login", post(login_handler))
+        .route("/api/refresh", post(refresh_handler))
+        .merge(SwaggerUi::new("/docs").url("/api/openapi.json", openapi::ApiDoc::openapi()))
+        .layer(CompressionLayer::new())
         .layer(CorsLayer::permissive())
         .with_state(state);
 
-    let addr = format!("0.0.0.0:{}", port);
+    let addr = format!("{}:{}", bind, port);
     let listener = TcpListener::bind(&addr).await.unwrap();
     println!("🚀 RustPanel Core running on http://{}", addr);
     axum::serve(listener, app).await.unwrap();
 }
 
-async fn list_files(Query(params): Query<FileQuery>) -> Result<Json<Vec<FileInfo>>, StatusCode> {
-    let default_path = ".".to_string();
-    let path_str = params.path.as_ref().unwrap_or(&default_path);
-    let path = Path::new(path_str);
-
-    if path_str.contains("..") {
-        return Err(StatusCode::FORBIDDEN);
-    }
-
-    match fs::read_dir(path) {
-        Ok(entries) => {
-            let mut files = Vec::new();
-            for entry in entries.flatten() {
-                if let Ok(metadata) = entry.metadata() {
-                    files.push(FileInfo {
-                        name: entry.file_name().to_string_lossy().to_string(),
-                        is_dir: metadata.is_dir(),
-                        size: metadata.len(),
-                    });
-                }
-            }
-            files.sort_by(|a, b| {
-                if a.is_dir == b.is_dir {
-                    a.name.to_lowercase().cmp(&b.name.to_lowercase())
-                } else {
-                    b.is_dir.cmp(&a.is_dir)
-                }
-            });
-            Ok(Json(files))
-        },
-        Err(_) => Err(StatusCode::NOT_FOUND),
-    }
-}
-
-async fn get_services() -> Json<Vec<ServiceInfo>> {
-    let services_to_check = ["nginx", "mysql", "ssh", "docker", "cron"];
-    let mut services = Vec::new();
-
-    for service_name in services_to_check {
-        let output = std::process::Command::new("systemctl")
-            .arg("is-active")
-            .arg(service_name)
-            .output();
-
-        let status = match output {
-            Ok(out) => {
-                if out.status.success() {
-                    "active".to_string()
-                } else {
-                    "inactive".to_string()
-                }
-            }
-            Err(_) => "unknown".to_string(),
-        };
-
-        services.push(ServiceInfo {
-            name: service_name.to_string(),
-            status,
-            description: format!("System service: {}", service_name),
-        });
-    }
-
-    Json(services)
-}
-
+#[utoipa::path(
+    get,
+    path = "/api/processes",
+    responses((status = 200, body = Vec<ProcessInfo>)),
+    security(("bearer_auth" = []))
+)]
 async fn get_processes(State(state): State<AppState>) -> Json<Vec<ProcessInfo>> {
     let mut sys = state.sys.lock().unwrap();
     sys.refresh_processes();
@@ -228,73 +172,12 @@ async fn get_processes(State(state): State<AppState>) -> Json<Vec<ProcessInfo>>
     Json(processes)
 }
 
-async fn login_handler(
-    State(state): State<AppState>,
-    Json(payload): Json<LoginRequest>
-) -> Result<Json<LoginResponse>, StatusCode> {
-    let user = sqlx::query_as::<_, (String,)>("SELECT password FROM users WHERE username = ?")
-        .bind(&payload.username)
-        .fetch_optional(&state.db)
-        .await
-        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
-
-    if let Some((hashed_password,)) = user {
-        if verify(&payload.password, &hashed_password).unwrap_or(false) {
-            let expiration = SystemTime::now()
-                .duration_since(UNIX_EPOCH)
-                .unwrap()
-                .as_secs() as usize + 3600;
-
-            let claims = Claims {
-                sub: payload.username,
-                exp: expiration,
-            };
-
-            let token = encode(
-                &Header::default(), 
-                &claims, 
-                &EncodingKey::from_secret(state.jwt_secret.as_bytes())
-            ).map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
-
-            return Ok(Json(LoginResponse { token }));
-        }
-    }
-
-    Err(StatusCode::UNAUTHORIZED)
-}
-
-async fn auth_middleware(
-    State(state): State<AppState>,
-    req: Request, 
-    next: Next
-) -> Result<Response, StatusCode> {
-    let auth_header = req.headers().get(header::AUTHORIZATION)
-        .and_then(|header| header.to_str().ok());
-
-    let auth_header = if let Some(auth_header) = auth_header {
-        auth_header
-    } else {
-        return Err(StatusCode::UNAUTHORIZED);
-    };
-
-    if let Some(token) = auth_header.strip_prefix("Bearer ") {
-        let validation = Validation::default();
-        let token_data = decode::<Claims>(
-            token, 
-            &DecodingKey::from_secret(state.jwt_secret.as_bytes()), 
-            &validation
-        );
-
-        if token_data.is_ok() {
-            Ok(next.run(req).await)
-        } else {
-            Err(StatusCode::UNAUTHORIZED)
-        }
-    } else {
-        Err(StatusCode::UNAUTHORIZED)
-    }
-}
-
+#[utoipa::path(
+    get,
+    path = "/api/system",
+    responses((status = 200, body = SystemMetrics)),
+    security(("bearer_auth" = []))
+)]
 async fn get_system_metrics(State(state): State<AppState>) -> Json<SystemMetrics> {
     let mut sys = state.sys.lock().unwrap();
     let mut disks = state.disks.lock().unwrap();
@@ -0,0 +1,41 @@
+use utoipa::openapi::security::{HttpAuthScheme, HttpBuilder, SecurityScheme};
+use utoipa::{Modify, OpenApi};
+
+#[derive(OpenApi)]
+#[openapi(
+    paths(
+        crate::get_system_metrics,
+        crate::get_processes,
+        crate::services::get_services,
+        crate::services::control_service,
+        crate::files::list_files,
+        crate::files::download_file,
+        crate::files::delete_file,
+        crate::files::upload_file,
+        crate::auth::login_handler,
+    ),
+    components(schemas(
+        crate::SystemMetrics,
+        crate::ProcessInfo,
+        crate::services::ServiceInfo,
+        crate::services::ServiceActionResult,
+        crate::files::FileInfo,
+        crate::auth::LoginRequest,
+        crate::auth::LoginResponse,
+    )),
+    modifiers(&SecurityAddon),
+    tags((name = "rustpanel", description = "System monitoring and control API"))
+)]
+pub struct ApiDoc;
+
+struct SecurityAddon;
+
+impl Modify for SecurityAddon {
+    fn modify(&self, openapi: &mut utoipa::openapi::OpenApi) {
+        let components = openapi.components.as_mut().expect("components registered above");
+        components.add_security_scheme(
+            "bearer_auth",
+            SecurityScheme::Http(HttpBuilder::new().scheme(HttpAuthScheme::Bearer).bearer_format("JWT").build()),
+        );
+    }
+}
@@ -0,0 +1,49 @@
+use argon2::password_hash::{PasswordHash, PasswordHasher as _, PasswordVerifier, SaltString};
+use argon2::{Algorithm, Argon2, Params, Version};
+
+/// Which algorithm produced a given stored hash, detected from its PHC
+/// prefix (`$argon2id$...` vs bcrypt's `$2a$`/`$2b$`/`$2y$`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HashAlgorithm {
+    Argon2id,
+    Bcrypt,
+}
+
+pub fn detect_algorithm(stored: &str) -> HashAlgorithm {
+    if stored.starts_with("$argon2id$") {
+        HashAlgorithm::Argon2id
+    } else {
+        HashAlgorithm::Bcrypt
+    }
+}
+
+/// 19 MiB memory, 2 iterations, 1 degree of parallelism — OWASP's baseline
+/// recommendation for Argon2id on a server with modest memory budget.
+fn argon2() -> Argon2<'static> {
+    let params = Params::new(19 * 1024, 2, 1, None).expect("valid argon2 params");
+    Argon2::new(Algorithm::Argon2id, Version::V0x13, params)
+}
+
+/// Hashes a new password with Argon2id, returning the full PHC string so
+/// the parameters travel alongside the hash.
+pub fn hash_password(password: &str) -> String {
+    let salt = SaltString::generate(&mut rand::thread_rng());
+    argon2()
+        .hash_password(password.as_bytes(), &salt)
+        .expect("argon2 hashing should not fail")
+        .to_string()
+}
+
+/// Verifies `password` against `stored`, dispatching to the algorithm that
+/// produced it.
+pub fn verify_password(password: &str, stored: &str) -> bool {
+    match detect_algorithm(stored) {
+        HashAlgorithm::Argon2id => {
+            let Ok(parsed) = PasswordHash::new(stored) else {
+                return false;
+            };
+            argon2().verify_password(password.as_bytes(), &parsed).is_ok()
+        }
+        HashAlgorithm::Bcrypt => bcrypt::verify(password, stored).unwrap_or(false),
+    }
+}
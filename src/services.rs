@@ -0,0 +1,104 @@
+use axum::{
+    extract::{Path, State},
+    Json,
+};
+use serde::Serialize;
+use std::process::Output;
+
+use crate::error::ApiError;
+use crate::AppState;
+
+const ALLOWED_ACTIONS: [&str; 5] = ["start", "stop", "restart", "enable", "disable"];
+
+#[derive(Serialize, utoipa::ToSchema)]
+pub struct ServiceInfo {
+    name: String,
+    status: String,
+    description: String,
+}
+
+#[derive(Serialize, utoipa::ToSchema)]
+pub struct ServiceActionResult {
+    service: String,
+    action: String,
+    status: String,
+    stdout: String,
+    stderr: String,
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/services",
+    responses((status = 200, body = Vec<ServiceInfo>)),
+    security(("bearer_auth" = []))
+)]
+pub async fn get_services(State(state): State<AppState>) -> Json<Vec<ServiceInfo>> {
+    let mut services = Vec::new();
+
+    for service_name in state.config.services.monitored.clone() {
+        let output = std::process::Command::new("systemctl")
+            .arg("is-active")
+            .arg(&service_name)
+            .output();
+
+        let status = match output {
+            Ok(out) => {
+                if out.status.success() {
+                    "active".to_string()
+                } else {
+                    "inactive".to_string()
+                }
+            }
+            Err(_) => "unknown".to_string(),
+        };
+
+        services.push(ServiceInfo {
+            description: format!("System service: {}", service_name),
+            name: service_name,
+            status,
+        });
+    }
+
+    Json(services)
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/services/{name}/{action}",
+    params(
+        ("name" = String, Path, description = "Service name, must be in the monitored allowlist"),
+        ("action" = String, Path, description = "One of start, stop, restart, enable, disable"),
+    ),
+    responses(
+        (status = 200, body = ServiceActionResult),
+        (status = 400, description = "Unknown action"),
+        (status = 403, description = "Service is not in the monitored allowlist"),
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn control_service(
+    State(state): State<AppState>,
+    Path((name, action)): Path<(String, String)>,
+) -> Result<Json<ServiceActionResult>, ApiError> {
+    if !state.config.services.monitored.iter().any(|s| s == &name) {
+        return Err(ApiError::Forbidden);
+    }
+
+    if !ALLOWED_ACTIONS.contains(&action.as_str()) {
+        return Err(ApiError::BadRequest(format!("unknown action '{action}'")));
+    }
+
+    let output: Output = std::process::Command::new("systemctl")
+        .arg(&action)
+        .arg(&name)
+        .output()
+        .map_err(|err| ApiError::BadRequest(format!("failed to invoke systemctl: {err}")))?;
+
+    Ok(Json(ServiceActionResult {
+        service: name,
+        action,
+        status: if output.status.success() { "ok".to_string() } else { "failed".to_string() },
+        stdout: String::from_utf8_lossy(&output.stdout).into_owned(),
+        stderr: String::from_utf8_lossy(&output.stderr).into_owned(),
+    }))
+}